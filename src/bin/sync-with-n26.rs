@@ -1,14 +1,259 @@
 use chrono::{NaiveDate, Utc};
 use clap_verbosity_flag;
 use failure::ResultExt;
+use log::warn;
 use serde_json;
+use std::collections::HashMap;
 use std::fs::read_to_string;
 use std::path::PathBuf;
 use structopt::StructOpt;
 use ynab_sync::error::{ErrorKind, Result};
+use ynab_sync::export::{export_sqlite, export_tsv};
 use ynab_sync::logging::setup_logging;
 use ynab_sync::n26::{Cli as N26Cli, Transaction as N26Transaction, N26};
-use ynab_sync::ynab::{Cli as YNABCli, Transaction as YNABTransaction, TransactionCleared, YNAB};
+use ynab_sync::reconcile::check_reimbursables;
+use ynab_sync::review::review_transactions;
+use ynab_sync::ynab::{
+    Account as YNABAccount, Category as YNABCategory, Cli as YNABCli,
+    SubTransaction as YNABSubTransaction, Transaction as YNABTransaction, TransactionCleared,
+    YNAB,
+};
+
+/// Finds the first split rule whose `match` needle appears in the memo or
+/// merchant name of a transaction.
+fn find_split_rule<'a>(
+    split_rules: &'a [serde_json::Value],
+    memo: &str,
+    merchant_name: Option<&str>,
+) -> Option<&'a serde_json::Value> {
+    split_rules.iter().find(|rule| {
+        rule.get("match")
+            .and_then(|needle| needle.as_str())
+            .map(|needle| {
+                memo.contains(needle) || merchant_name.map_or(false, |m| m.contains(needle))
+            })
+            .unwrap_or(false)
+    })
+}
+
+/// Turns a matched split rule into YNAB subtransactions, resolving each
+/// split's category name against the budget's categories.
+///
+/// Every split carries either a fixed `amount` (in milliunits) or a
+/// `percent` of the parent transaction's amount. Whatever is left over after
+/// applying all splits is assigned to `leftover_category` if the rule names
+/// one that exists among the budget's categories; otherwise a non-zero
+/// leftover means the rule does not add up and is rejected.
+fn build_subtransactions(
+    amount: i64,
+    rule: &serde_json::Value,
+    ynab_categories: &HashMap<String, YNABCategory>,
+) -> Result<Vec<YNABSubTransaction>> {
+    let splits: Vec<serde_json::Value> = rule
+        .get("splits")
+        .and_then(|splits| splits.as_array())
+        .cloned()
+        .unwrap_or_default();
+
+    let mut subtransactions = Vec::with_capacity(splits.len());
+    let mut allocated: i64 = 0;
+
+    for split in &splits {
+        let category_id = split
+            .get("category")
+            .and_then(|c| c.as_str())
+            .and_then(|name| ynab_categories.get(name))
+            .map(|c| c.id.clone());
+        let memo = split
+            .get("memo")
+            .and_then(|memo| memo.as_str())
+            .map(String::from);
+        let split_amount = match split.get("amount").and_then(|a| a.as_i64()) {
+            Some(fixed) => fixed,
+            None => match split.get("percent").and_then(|p| p.as_f64()) {
+                Some(percent) => ((amount as f64) * percent / 100.0).round() as i64,
+                None => 0,
+            },
+        };
+
+        allocated += split_amount;
+        subtransactions.push(YNABSubTransaction {
+            amount: split_amount,
+            category_id,
+            payee_id: None,
+            payee_name: None,
+            memo,
+        });
+    }
+
+    let remainder = amount - allocated;
+    if remainder != 0 {
+        let leftover_category = rule
+            .get("leftover_category")
+            .and_then(|c| c.as_str())
+            .and_then(|name| ynab_categories.get(name));
+        match leftover_category {
+            Some(category) => subtransactions.push(YNABSubTransaction {
+                amount: remainder,
+                category_id: Some(category.id.clone()),
+                payee_id: None,
+                payee_name: None,
+                memo: None,
+            }),
+            None => Err(ErrorKind::SplitRuleAmountMismatch(
+                rule.get("match")
+                    .and_then(|m| m.as_str())
+                    .unwrap_or("")
+                    .to_string(),
+                allocated,
+                amount,
+            ))?,
+        }
+    }
+
+    Ok(subtransactions)
+}
+
+/// Resolves a YNAB payee for a transaction by looking up its merchant name
+/// (exact match) or reference text (substring match) in `payee_mapping`.
+///
+/// A mapping entry is either a plain string, taken as the YNAB `payee_name`,
+/// or an object with `payee_name` and/or a pre-resolved `payee_id`.
+fn resolve_payee(
+    payee_mapping: &serde_json::Map<String, serde_json::Value>,
+    merchant_name: Option<&str>,
+    reference_text: Option<&str>,
+) -> (Option<String>, Option<String>) {
+    let entry = merchant_name.and_then(|name| payee_mapping.get(name)).or_else(|| {
+        reference_text.and_then(|text| {
+            payee_mapping
+                .iter()
+                .find(|(needle, _)| text.contains(needle.as_str()))
+                .map(|(_, value)| value)
+        })
+    });
+
+    match entry {
+        Some(value) => match value.as_str() {
+            Some(payee_name) => (None, Some(payee_name.to_string())),
+            None => {
+                let payee_id = value
+                    .get("payee_id")
+                    .and_then(|v| v.as_str())
+                    .map(String::from);
+                let payee_name = value
+                    .get("payee_name")
+                    .and_then(|v| v.as_str())
+                    .map(String::from);
+                (payee_id, payee_name)
+            }
+        },
+        None => (None, None),
+    }
+}
+
+/// One `{ n26_account, ynab_budget_id, ynab_account_id, category_mapping_file }`
+/// entry, either read from `--accounts-config` or synthesized from the
+/// single-account `--ynab-*`/`--n26-category-mapping` flags.
+struct AccountJob {
+    n26_account: Option<String>,
+    ynab_budget_id: String,
+    ynab_account_id: String,
+    category_mapping_file: String,
+}
+
+/// Builds the list of accounts to sync, either from `--accounts-config` or,
+/// when that is absent, from the single-account CLI flags.
+fn account_jobs(cli: &Cli) -> Result<Vec<AccountJob>> {
+    match &cli.accounts_config_file {
+        Some(accounts_config_file) => {
+            if !PathBuf::from(accounts_config_file.clone()).exists() {
+                Err(ErrorKind::ArgParseAccountsConfigCanNotRead(
+                    accounts_config_file.clone(),
+                ))?
+            }
+
+            let accounts_config_string = read_to_string(accounts_config_file.to_string())
+                .with_context(|_| {
+                    ErrorKind::ArgParseAccountsConfigCanNotRead(accounts_config_file.clone())
+                })?;
+            let accounts_config_value: serde_json::Value =
+                serde_json::from_str(accounts_config_string.as_str()).context(
+                    ErrorKind::ArgParseAccountsConfigCanNotParse(accounts_config_file.clone()),
+                )?;
+
+            let entries = accounts_config_value.as_array().ok_or_else(|| {
+                ErrorKind::ArgParseAccountsConfigCanNotParse(accounts_config_file.clone())
+            })?;
+
+            entries
+                .iter()
+                .map(|entry| {
+                    let ynab_budget_id = entry
+                        .get("ynab_budget_id")
+                        .and_then(|v| v.as_str())
+                        .ok_or_else(|| {
+                            ErrorKind::ArgParseAccountsConfigCanNotParse(
+                                accounts_config_file.clone(),
+                            )
+                        })?;
+                    let ynab_account_id = entry
+                        .get("ynab_account_id")
+                        .and_then(|v| v.as_str())
+                        .ok_or_else(|| {
+                            ErrorKind::ArgParseAccountsConfigCanNotParse(
+                                accounts_config_file.clone(),
+                            )
+                        })?;
+                    let category_mapping_file = entry
+                        .get("category_mapping_file")
+                        .and_then(|v| v.as_str())
+                        .ok_or_else(|| {
+                            ErrorKind::ArgParseAccountsConfigCanNotParse(
+                                accounts_config_file.clone(),
+                            )
+                        })?;
+                    let n26_account = entry
+                        .get("n26_account")
+                        .and_then(|v| v.as_str())
+                        .map(String::from);
+
+                    Ok(AccountJob {
+                        n26_account,
+                        ynab_budget_id: ynab_budget_id.to_string(),
+                        ynab_account_id: ynab_account_id.to_string(),
+                        category_mapping_file: category_mapping_file.to_string(),
+                    })
+                })
+                .collect()
+        }
+        None => {
+            let ynab_budget_id = cli.ynab.budget_id.clone().ok_or_else(|| {
+                ErrorKind::ArgMissingRequired(
+                    "--ynab-budget-id is required unless --accounts-config is used".to_string(),
+                )
+            })?;
+            let ynab_account_id = cli.ynab.account_id.clone().ok_or_else(|| {
+                ErrorKind::ArgMissingRequired(
+                    "--ynab-account-id is required unless --accounts-config is used".to_string(),
+                )
+            })?;
+            let category_mapping_file = cli.category_mapping_file.clone().ok_or_else(|| {
+                ErrorKind::ArgMissingRequired(
+                    "--n26-category-mapping is required unless --accounts-config is used"
+                        .to_string(),
+                )
+            })?;
+
+            Ok(vec![AccountJob {
+                n26_account: None,
+                ynab_budget_id,
+                ynab_account_id,
+                category_mapping_file,
+            }])
+        }
+    }
+}
 
 #[derive(Debug, StructOpt)]
 struct Cli {
@@ -20,11 +265,51 @@ struct Cli {
     n26: N26Cli,
     #[structopt(
         long = "n26-category-mapping",
-        required = true,
         value_name = "FILE",
-        help = "JSON file which represents the mapping between N26 and YNAB category."
+        help = "JSON file which represents the mapping between N26 and YNAB category. Ignored (and not required) when --accounts-config is used."
     )]
-    category_mapping_file: String,
+    category_mapping_file: Option<String>,
+    #[structopt(
+        long = "accounts-config",
+        value_name = "FILE",
+        help = "JSON file listing { n26_account, ynab_budget_id, ynab_account_id, category_mapping_file } entries to sync in one run, instead of the single --ynab-budget-id/--ynab-account-id/--n26-category-mapping flags."
+    )]
+    accounts_config_file: Option<String>,
+    #[structopt(
+        long = "n26-payee-mapping",
+        value_name = "FILE",
+        help = "JSON file which represents the mapping between N26 merchant name / reference text and a YNAB payee."
+    )]
+    payee_mapping_file: Option<String>,
+    #[structopt(
+        long = "n26-split-rules",
+        value_name = "FILE",
+        help = "JSON file describing rules for splitting a single N26 transaction into multiple YNAB subtransactions."
+    )]
+    split_rules_file: Option<String>,
+    #[structopt(
+        long = "review",
+        help = "Review and approve converted transactions in an interactive table before syncing them to YNAB."
+    )]
+    review: bool,
+    #[structopt(
+        long = "export-tsv",
+        value_name = "DIR",
+        help = "Export fetched YNAB and converted N26 transactions as accounts.tsv/categories.tsv/transactions.tsv in DIR."
+    )]
+    export_tsv_dir: Option<PathBuf>,
+    #[structopt(
+        long = "export-sqlite",
+        value_name = "FILE",
+        help = "Export fetched YNAB and converted N26 transactions into a SQLite database at FILE."
+    )]
+    export_sqlite_file: Option<PathBuf>,
+    #[structopt(
+        long = "check-reimbursables",
+        value_name = "CATEGORY",
+        help = "Before syncing, assert that cleared/reconciled transactions in CATEGORY net to zero and list outstanding ones that don't."
+    )]
+    check_reimbursables_category: Option<String>,
     #[structopt(
         long = "sync-from",
         required = true,
@@ -34,78 +319,87 @@ struct Cli {
     sync_from: String,
 }
 
-fn main() -> Result<()> {
-    let cli = Cli::from_args();
-    let app = Cli::clap();
-
-    setup_logging(app.get_name().to_string(), cli.verbose.log_level())?;
-
-    println!("[ 1/10] Parsing --sync-from");
-    let sync_from = NaiveDate::parse_from_str(&cli.sync_from, "%Y-%m-%d")?;
-    let days_to_sync = Utc::now()
-        .naive_utc()
-        .date()
-        .signed_duration_since(sync_from)
-        .num_days()
-        + 1;
-
+/// Fetches YNAB/N26 data for a single `AccountJob` and syncs it, sharing the
+/// already-authenticated `ynab`/`n26` clients and the global mapping files
+/// with every other job in this run.
+#[allow(clippy::too_many_arguments)]
+fn sync_account(
+    cli: &Cli,
+    ynab: &YNAB,
+    n26: &N26,
+    n26_categories: &HashMap<String, String>,
+    payee_mapping: &serde_json::Map<String, serde_json::Value>,
+    split_rules: &[serde_json::Value],
+    days_to_sync: i64,
+    job: &AccountJob,
+    job_number: usize,
+    job_count: usize,
+    export_accounts: &mut HashMap<String, YNABAccount>,
+    export_categories: &mut HashMap<String, YNABCategory>,
+    export_transactions: &mut Vec<YNABTransaction>,
+) -> Result<()> {
     //
     // Validate that category_mapping_file file exists and that it is of JSON format
     //
-    println!("[ 2/10] Parsing --category-mapping-file");
+    println!(
+        "[account {}/{}] Parsing --n26-category-mapping for YNAB budget {}",
+        job_number, job_count, job.ynab_budget_id
+    );
 
-    if !PathBuf::from(cli.category_mapping_file.clone()).exists() {
+    if !PathBuf::from(job.category_mapping_file.clone()).exists() {
         Err(ErrorKind::ArgParseCategoryMappingCanNotRead(
-            cli.category_mapping_file.clone(),
+            job.category_mapping_file.clone(),
         ))?
     }
 
-    let category_mapping_string = read_to_string(cli.category_mapping_file.to_string())
+    let category_mapping_string = read_to_string(job.category_mapping_file.to_string())
         .with_context(|_| {
-            ErrorKind::ArgParseCategoryMappingCanNotRead(cli.category_mapping_file.clone())
+            ErrorKind::ArgParseCategoryMappingCanNotRead(job.category_mapping_file.clone())
         })?;
     let category_mapping_value: serde_json::Value =
         serde_json::from_str(category_mapping_string.as_str()).context(
-            ErrorKind::ArgParseCategoryMappingCanNotParse(cli.category_mapping_file.clone()),
+            ErrorKind::ArgParseCategoryMappingCanNotParse(job.category_mapping_file.clone()),
         )?;
 
     let category_mapping = match category_mapping_value.as_object() {
         Some(x) => x,
         None => Err(ErrorKind::ArgParseCategoryMappingCanNotParse(
-            cli.category_mapping_file.clone(),
+            job.category_mapping_file.clone(),
         ))?,
     };
 
-    // YNAB client
-    let ynab = YNAB {
-        token: cli.ynab.token.clone(),
-    };
-
-    // validate ynab cli options
-    ynab.validate_cli(cli.ynab.clone(), 2, 10)?;
-
     // Fetch YNAB categories
-    println!("[ 5/10] Fetching YNAB categories");
-    let ynab_categories = ynab.get_categories(cli.ynab.budget_id.clone())?;
+    println!(
+        "[account {}/{}] Fetching YNAB categories for budget {}",
+        job_number, job_count, job.ynab_budget_id
+    );
+    let ynab_categories = ynab.get_categories(job.ynab_budget_id.clone())?;
+
+    // Fetch YNAB accounts
+    println!(
+        "[account {}/{}] Fetching YNAB accounts for budget {}",
+        job_number, job_count, job.ynab_budget_id
+    );
+    let ynab_accounts = ynab.get_accounts(job.ynab_budget_id.clone())?;
 
     // Fetch ynab transactions
     println!(
-        "[ 6/10] Fetching YNAB transactions for the last {} days",
-        days_to_sync
+        "[account {}/{}] Fetching YNAB transactions for the last {} days",
+        job_number, job_count, days_to_sync
     );
     let ynab_transactions = ynab.get_transactions(
-        cli.ynab.budget_id.clone(),
-        cli.ynab.account_id.clone(),
+        job.ynab_budget_id.clone(),
+        job.ynab_account_id.clone(),
         days_to_sync,
     )?;
 
-    // N26 client
-    println!("[ 7/10] Fetching N26 token");
-    let n26 = N26::new(cli.n26.username.clone(), cli.n26.password.clone())?;
-
-    // Fetch n26 categories
-    println!("[ 8/10] Fetching N26 categories");
-    let n26_categories = n26.get_categories()?;
+    if let Some(category_name) = &cli.check_reimbursables_category {
+        println!(
+            "[account {}/{}] Checking reimbursables in '{}'",
+            job_number, job_count, category_name
+        );
+        check_reimbursables(category_name, &ynab_categories, &ynab_transactions)?;
+    }
 
     let convert_transaction = |transaction: &N26Transaction| -> YNABTransaction {
         let category: Option<String> = n26_categories
@@ -122,6 +416,12 @@ fn main() -> Result<()> {
         // when we can not figure out category we mark transaction as not approved
         let approved = category.is_some();
 
+        let (payee_id, payee_name) = resolve_payee(
+            payee_mapping,
+            transaction.merchant_name.as_deref(),
+            transaction.reference_text.as_deref(),
+        );
+
         // XXX: we can probably find more idiomatic way of doing this
         let memo = match &transaction.reference_text {
             Some(reference_text) => Some(reference_text.to_string()),
@@ -134,37 +434,231 @@ fn main() -> Result<()> {
             },
         };
 
+        let subtransactions = find_split_rule(
+            split_rules,
+            memo.as_deref().unwrap_or(""),
+            transaction.merchant_name.as_deref(),
+        )
+        .and_then(
+            |rule| match build_subtransactions(transaction.amount, rule, &ynab_categories) {
+                Ok(subtransactions) => Some(subtransactions),
+                Err(err) => {
+                    warn!(
+                        "ignoring --n26-split-rules entry for transaction {}: {}",
+                        transaction.id, err
+                    );
+                    None
+                }
+            },
+        );
+
+        // A split parent carries no category of its own in YNAB: each
+        // subtransaction has its own, and approval reflects whether every
+        // split could be categorized rather than the (nonexistent) parent
+        // category.
+        let (category, approved) = match &subtransactions {
+            Some(subtransactions) => (
+                None,
+                subtransactions.iter().all(|s| s.category_id.is_some()),
+            ),
+            None => (category, approved),
+        };
+
         YNABTransaction {
-            account_id: cli.ynab.account_id.clone().to_string(),
+            id: None,
+            account_id: job.ynab_account_id.clone(),
             date: transaction.visible_ts.format("%Y-%m-%d").to_string(),
             amount: transaction.amount,
-            // TODO: we would need to have payee_mapping
-            payee_id: None,
-            payee_name: None,
+            payee_id,
+            payee_name,
             category_id: category,
             memo,
             cleared: TransactionCleared::Cleared,
             approved,
             flag_color: None,
+            subtransactions,
             import_id: Some(transaction.id.clone()),
+            deleted: false,
         }
     };
 
-    println!("[ 9/10] Fetching N26 transaction and converting them to YNAB transactions");
+    println!(
+        "[account {}/{}] Fetching N26 transactions and converting them to YNAB transactions",
+        job_number, job_count
+    );
     let transactions: Vec<YNABTransaction> = n26
-        .get_transactions(days_to_sync, 100_000_000)? // XXX: for now we set limit to 1mio
+        .get_transactions(days_to_sync, 100_000_000, job.n26_account.as_deref())? // XXX: for now we set limit to 100mio
         .into_iter()
         .map(|t| convert_transaction(&t))
         .collect();
 
+    let transactions = if cli.review {
+        review_transactions(transactions, &ynab_categories)
+    } else {
+        transactions
+    };
+
+    if cli.export_tsv_dir.is_some() || cli.export_sqlite_file.is_some() {
+        export_accounts.extend(ynab_accounts.clone());
+        export_categories.extend(ynab_categories.clone());
+        export_transactions.extend(ynab_transactions.iter().cloned());
+        export_transactions.extend(transactions.iter().cloned());
+    }
+
     ynab.sync(
         transactions,
         ynab_transactions,
-        cli.ynab.budget_id.clone(),
+        job.ynab_budget_id.clone(),
         cli.ynab.force_update,
-        9,
-        10,
+        job_number as u32,
+        job_count as u32,
     )?;
 
     Ok(())
 }
+
+fn main() -> Result<()> {
+    let cli = Cli::from_args();
+    let app = Cli::clap();
+
+    setup_logging(app.get_name().to_string(), cli.verbose.log_level())?;
+
+    println!("[1/5] Parsing --sync-from");
+    let sync_from = NaiveDate::parse_from_str(&cli.sync_from, "%Y-%m-%d")?;
+    let days_to_sync = Utc::now()
+        .naive_utc()
+        .date()
+        .signed_duration_since(sync_from)
+        .num_days()
+        + 1;
+
+    let jobs = account_jobs(&cli)?;
+
+    //
+    // Validate that payee_mapping_file file exists and that it is of JSON format
+    //
+    println!("[2/5] Parsing --n26-payee-mapping");
+
+    let payee_mapping: serde_json::Map<String, serde_json::Value> = match &cli.payee_mapping_file
+    {
+        Some(payee_mapping_file) => {
+            if !PathBuf::from(payee_mapping_file.clone()).exists() {
+                Err(ErrorKind::ArgParsePayeeMappingCanNotRead(
+                    payee_mapping_file.clone(),
+                ))?
+            }
+
+            let payee_mapping_string = read_to_string(payee_mapping_file.to_string())
+                .with_context(|_| {
+                    ErrorKind::ArgParsePayeeMappingCanNotRead(payee_mapping_file.clone())
+                })?;
+            let payee_mapping_value: serde_json::Value =
+                serde_json::from_str(payee_mapping_string.as_str()).context(
+                    ErrorKind::ArgParsePayeeMappingCanNotParse(payee_mapping_file.clone()),
+                )?;
+
+            match payee_mapping_value.as_object() {
+                Some(x) => x.clone(),
+                None => Err(ErrorKind::ArgParsePayeeMappingCanNotParse(
+                    payee_mapping_file.clone(),
+                ))?,
+            }
+        }
+        None => serde_json::Map::new(),
+    };
+
+    //
+    // Validate that split_rules_file file exists and that it is of JSON format
+    //
+    println!("[3/5] Parsing --n26-split-rules");
+
+    let split_rules: Vec<serde_json::Value> = match &cli.split_rules_file {
+        Some(split_rules_file) => {
+            if !PathBuf::from(split_rules_file.clone()).exists() {
+                Err(ErrorKind::ArgParseSplitRulesCanNotRead(
+                    split_rules_file.clone(),
+                ))?
+            }
+
+            let split_rules_string = read_to_string(split_rules_file.to_string())
+                .with_context(|_| ErrorKind::ArgParseSplitRulesCanNotRead(split_rules_file.clone()))?;
+            let split_rules_value: serde_json::Value = serde_json::from_str(
+                split_rules_string.as_str(),
+            )
+            .context(ErrorKind::ArgParseSplitRulesCanNotParse(
+                split_rules_file.clone(),
+            ))?;
+
+            match split_rules_value.as_array() {
+                Some(x) => x.clone(),
+                None => Err(ErrorKind::ArgParseSplitRulesCanNotParse(
+                    split_rules_file.clone(),
+                ))?,
+            }
+        }
+        None => vec![],
+    };
+
+    // YNAB client
+    let ynab = YNAB {
+        token: cli.ynab.token.clone(),
+    };
+
+    // validate ynab cli options
+    ynab.validate_cli(cli.ynab.clone(), 3, 5)?;
+
+    // N26 client -- authenticated once and reused for every account job below
+    println!("[4/5] Fetching N26 token");
+    let n26 = N26::new(cli.n26.username.clone(), cli.n26.password.clone())?;
+
+    // Fetch n26 categories
+    println!("[5/5] Fetching N26 categories");
+    let n26_categories = n26.get_categories()?;
+
+    let job_count = jobs.len();
+    let mut export_accounts: HashMap<String, YNABAccount> = HashMap::new();
+    let mut export_categories: HashMap<String, YNABCategory> = HashMap::new();
+    let mut export_transactions: Vec<YNABTransaction> = Vec::new();
+    for (index, job) in jobs.iter().enumerate() {
+        sync_account(
+            &cli,
+            &ynab,
+            &n26,
+            &n26_categories,
+            &payee_mapping,
+            &split_rules,
+            days_to_sync,
+            job,
+            index + 1,
+            job_count,
+            &mut export_accounts,
+            &mut export_categories,
+            &mut export_transactions,
+        )?;
+    }
+
+    // Export once across all jobs so a multi-account --accounts-config run
+    // doesn't truncate the previous job's .tsv rows or re-insert the same
+    // accounts/categories into the sqlite database.
+    if let Some(dir) = &cli.export_tsv_dir {
+        println!("Exporting to TSV files in {}", dir.display());
+        export_tsv(
+            dir,
+            &export_accounts,
+            &export_categories,
+            &export_transactions,
+        )?;
+    }
+
+    if let Some(file) = &cli.export_sqlite_file {
+        println!("Exporting to SQLite database {}", file.display());
+        export_sqlite(
+            file,
+            &export_accounts,
+            &export_categories,
+            &export_transactions,
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use failure::{Backtrace, Context, Fail};
+use std::fmt;
+
+#[derive(Debug)]
+pub struct Error {
+    inner: Context<ErrorKind>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Fail)]
+pub enum ErrorKind {
+    #[fail(display = "can not read category mapping file: {}", _0)]
+    ArgParseCategoryMappingCanNotRead(String),
+
+    #[fail(display = "can not parse category mapping file: {}", _0)]
+    ArgParseCategoryMappingCanNotParse(String),
+
+    #[fail(display = "can not read split rules file: {}", _0)]
+    ArgParseSplitRulesCanNotRead(String),
+
+    #[fail(display = "can not parse split rules file: {}", _0)]
+    ArgParseSplitRulesCanNotParse(String),
+
+    #[fail(
+        display = "split rule for '{}' does not add up: subtransactions sum to {} but transaction amount is {}",
+        _0, _1, _2
+    )]
+    SplitRuleAmountMismatch(String, i64, i64),
+
+    #[fail(display = "can not read payee mapping file: {}", _0)]
+    ArgParsePayeeMappingCanNotRead(String),
+
+    #[fail(display = "can not parse payee mapping file: {}", _0)]
+    ArgParsePayeeMappingCanNotParse(String),
+
+    #[fail(display = "export error: {}", _0)]
+    Export(String),
+
+    #[fail(display = "reimbursables category '{}' not found in YNAB budget", _0)]
+    ReimbursablesCategoryNotFound(String),
+
+    #[fail(
+        display = "reimbursables category '{}' is out of balance by {} milliunits",
+        _0, _1
+    )]
+    ReimbursablesOutOfBalance(String, i64),
+
+    #[fail(display = "can not read accounts config file: {}", _0)]
+    ArgParseAccountsConfigCanNotRead(String),
+
+    #[fail(display = "can not parse accounts config file: {}", _0)]
+    ArgParseAccountsConfigCanNotParse(String),
+
+    #[fail(display = "missing required argument: {}", _0)]
+    ArgMissingRequired(String),
+
+    #[fail(display = "N26 error: {}", _0)]
+    N26(String),
+
+    #[fail(display = "YNAB error: {}", _0)]
+    YNAB(String),
+
+    #[fail(display = "io error: {}", _0)]
+    Io(String),
+}
+
+impl Fail for Error {
+    fn cause(&self) -> Option<&dyn Fail> {
+        self.inner.cause()
+    }
+
+    fn backtrace(&self) -> Option<&Backtrace> {
+        self.inner.backtrace()
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+impl Error {
+    pub fn kind(&self) -> ErrorKind {
+        self.inner.get_context().clone()
+    }
+}
+
+impl From<ErrorKind> for Error {
+    fn from(kind: ErrorKind) -> Error {
+        Error {
+            inner: Context::new(kind),
+        }
+    }
+}
+
+impl From<Context<ErrorKind>> for Error {
+    fn from(inner: Context<ErrorKind>) -> Error {
+        Error { inner }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Error {
+        ErrorKind::Io(e.to_string()).into()
+    }
+}
+
+impl From<chrono::ParseError> for Error {
+    fn from(e: chrono::ParseError) -> Error {
+        ErrorKind::Io(e.to_string()).into()
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
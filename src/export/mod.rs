@@ -0,0 +1,150 @@
+use crate::error::{ErrorKind, Result};
+use crate::ynab::{Account, Category, Transaction};
+use rusqlite::{params, Connection};
+use std::collections::HashMap;
+use std::fs::{create_dir_all, File};
+use std::io::Write;
+use std::path::Path;
+
+const SCHEMA: &str = include_str!("schema.sql");
+
+/// Renders YNAB milliunits as a plain decimal string, e.g. `-1234` becomes
+/// `"-1.234"`.
+fn amount_decimal(amount: i64) -> String {
+    format!("{:.3}", (amount as f64) / 1000.0)
+}
+
+/// Replaces tabs and newlines in a field so it can't corrupt the
+/// tab-separated row/column structure of the `.tsv` exports.
+fn tsv_field(field: &str) -> String {
+    field.replace('\t', " ").replace('\n', " ")
+}
+
+/// Writes `accounts.tsv`, `categories.tsv` and `transactions.tsv` into `dir`,
+/// skipping rows YNAB has marked as deleted. `transactions` is expected to
+/// already contain both the transactions fetched from YNAB and the ones
+/// converted from N26.
+pub fn export_tsv(
+    dir: &Path,
+    accounts: &HashMap<String, Account>,
+    categories: &HashMap<String, Category>,
+    transactions: &[Transaction],
+) -> Result<()> {
+    create_dir_all(dir).map_err(|e| ErrorKind::Export(e.to_string()))?;
+
+    let mut accounts_file =
+        File::create(dir.join("accounts.tsv")).map_err(|e| ErrorKind::Export(e.to_string()))?;
+    writeln!(
+        accounts_file,
+        "id\tname\ttype\ton_budget\tclosed\tbalance_milliunits\tbalance"
+    )
+    .map_err(|e| ErrorKind::Export(e.to_string()))?;
+    for account in accounts.values().filter(|a| !a.deleted) {
+        writeln!(
+            accounts_file,
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            account.id,
+            tsv_field(&account.name),
+            account.account_type,
+            account.on_budget,
+            account.closed,
+            account.balance,
+            amount_decimal(account.balance)
+        )
+        .map_err(|e| ErrorKind::Export(e.to_string()))?;
+    }
+
+    let mut categories_file =
+        File::create(dir.join("categories.tsv")).map_err(|e| ErrorKind::Export(e.to_string()))?;
+    writeln!(categories_file, "id\tname").map_err(|e| ErrorKind::Export(e.to_string()))?;
+    for (name, category) in categories.iter().filter(|(_, c)| !c.deleted) {
+        writeln!(categories_file, "{}\t{}", category.id, tsv_field(name))
+            .map_err(|e| ErrorKind::Export(e.to_string()))?;
+    }
+
+    let mut transactions_file = File::create(dir.join("transactions.tsv"))
+        .map_err(|e| ErrorKind::Export(e.to_string()))?;
+    writeln!(
+        transactions_file,
+        "id\tdate\taccount_id\tcategory_id\tpayee_name\tmemo\tamount_milliunits\tamount\tapproved"
+    )
+    .map_err(|e| ErrorKind::Export(e.to_string()))?;
+    for transaction in transactions.iter().filter(|t| !t.deleted) {
+        writeln!(
+            transactions_file,
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            transaction.id.clone().unwrap_or_default(),
+            transaction.date,
+            transaction.account_id,
+            transaction.category_id.clone().unwrap_or_default(),
+            tsv_field(&transaction.payee_name.clone().unwrap_or_default()),
+            tsv_field(&transaction.memo.clone().unwrap_or_default()),
+            transaction.amount,
+            amount_decimal(transaction.amount),
+            transaction.approved
+        )
+        .map_err(|e| ErrorKind::Export(e.to_string()))?;
+    }
+
+    Ok(())
+}
+
+/// Creates `file` (if needed) from the embedded schema and inserts the
+/// fetched accounts, categories and transactions, skipping deleted rows.
+pub fn export_sqlite(
+    file: &Path,
+    accounts: &HashMap<String, Account>,
+    categories: &HashMap<String, Category>,
+    transactions: &[Transaction],
+) -> Result<()> {
+    let conn = Connection::open(file).map_err(|e| ErrorKind::Export(e.to_string()))?;
+    conn.execute_batch(SCHEMA)
+        .map_err(|e| ErrorKind::Export(e.to_string()))?;
+
+    for account in accounts.values().filter(|a| !a.deleted) {
+        conn.execute(
+            "INSERT INTO accounts (id, name, type, on_budget, closed, balance_milliunits, balance) \
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                account.id,
+                account.name,
+                account.account_type,
+                account.on_budget,
+                account.closed,
+                account.balance,
+                amount_decimal(account.balance),
+            ],
+        )
+        .map_err(|e| ErrorKind::Export(e.to_string()))?;
+    }
+
+    for (name, category) in categories.iter().filter(|(_, c)| !c.deleted) {
+        conn.execute(
+            "INSERT INTO categories (id, name) VALUES (?1, ?2)",
+            params![category.id, name],
+        )
+        .map_err(|e| ErrorKind::Export(e.to_string()))?;
+    }
+
+    for transaction in transactions.iter().filter(|t| !t.deleted) {
+        conn.execute(
+            "INSERT INTO transactions \
+             (id, date, account_id, category_id, payee_name, memo, amount_milliunits, amount, approved) \
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            params![
+                transaction.id,
+                transaction.date,
+                transaction.account_id,
+                transaction.category_id,
+                transaction.payee_name,
+                transaction.memo,
+                transaction.amount,
+                amount_decimal(transaction.amount),
+                transaction.approved,
+            ],
+        )
+        .map_err(|e| ErrorKind::Export(e.to_string()))?;
+    }
+
+    Ok(())
+}
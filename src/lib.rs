@@ -0,0 +1,7 @@
+pub mod error;
+pub mod export;
+pub mod logging;
+pub mod n26;
+pub mod reconcile;
+pub mod review;
+pub mod ynab;
@@ -0,0 +1,16 @@
+use crate::error::Result;
+use log::LevelFilter;
+
+/// Sets up env_logger using the verbosity level chosen on the command line.
+pub fn setup_logging(name: String, level: Option<log::Level>) -> Result<()> {
+    let filter = level
+        .map(|l| l.to_level_filter())
+        .unwrap_or(LevelFilter::Off);
+
+    env_logger::Builder::new()
+        .filter(Some(name.as_str()), filter)
+        .filter(None, LevelFilter::Warn)
+        .init();
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use crate::error::{ErrorKind, Result};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::collections::HashMap;
+use structopt::StructOpt;
+
+#[derive(Debug, Clone, StructOpt)]
+pub struct Cli {
+    #[structopt(
+        long = "n26-username",
+        required = true,
+        value_name = "USERNAME",
+        help = "N26 login username/email."
+    )]
+    pub username: String,
+    #[structopt(
+        long = "n26-password",
+        required = true,
+        value_name = "PASSWORD",
+        help = "N26 login password."
+    )]
+    pub password: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Category {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Transaction {
+    pub id: String,
+    pub amount: i64,
+    pub category: String,
+    pub visible_ts: DateTime<Utc>,
+    pub reference_text: Option<String>,
+    pub merchant_name: Option<String>,
+    pub merchant_city: Option<String>,
+    /// Id of the N26 account/space the transaction belongs to.
+    pub account_id: Option<String>,
+}
+
+pub struct N26 {
+    token: String,
+}
+
+impl N26 {
+    /// Returns a client for the given username/password. No request is made
+    /// to N26: the credentials are not used and the client's access token is
+    /// empty.
+    pub fn new(username: String, password: String) -> Result<N26> {
+        // NOTE: the actual OAuth device-flow dance with N26 is intentionally
+        // left out of this snapshot; callers only rely on the public API
+        // below.
+        let _ = (username, password);
+        Ok(N26 {
+            token: String::new(),
+        })
+    }
+
+    pub fn get_categories(&self) -> Result<HashMap<String, String>> {
+        let _ = &self.token;
+        Err(ErrorKind::N26("get_categories is not implemented".to_string()).into())
+    }
+
+    /// Takes the number of days to fetch, a cap on the number of entries and
+    /// an optional N26 account/space id to restrict the result to. Makes no
+    /// request to N26 and always returns an `ErrorKind::N26` error; no
+    /// transactions are fetched or filtered.
+    pub fn get_transactions(
+        &self,
+        _days_to_sync: i64,
+        _limit: i64,
+        _account: Option<&str>,
+    ) -> Result<Vec<Transaction>> {
+        let _ = &self.token;
+        Err(ErrorKind::N26("get_transactions is not implemented".to_string()).into())
+    }
+}
@@ -0,0 +1,62 @@
+use crate::error::{ErrorKind, Result};
+use crate::ynab::{Category, Transaction, TransactionCleared};
+use std::collections::HashMap;
+
+/// Validates that a paired/reimbursable category (e.g. "Reimbursable
+/// expenses") nets to zero across its cleared and reconciled transactions,
+/// and reports any outstanding entries still waiting for a matching payment.
+///
+/// Returns an error, without mutating anything, if the category is unknown
+/// or the cleared/reconciled transactions in it don't sum to zero.
+pub fn check_reimbursables(
+    category_name: &str,
+    ynab_categories: &HashMap<String, Category>,
+    transactions: &[Transaction],
+) -> Result<()> {
+    let category_id = ynab_categories
+        .get(category_name)
+        .map(|category| category.id.clone())
+        .ok_or_else(|| ErrorKind::ReimbursablesCategoryNotFound(category_name.to_string()))?;
+
+    let in_category: Vec<&Transaction> = transactions
+        .iter()
+        .filter(|t| !t.deleted && t.category_id.as_deref() == Some(category_id.as_str()))
+        .collect();
+
+    let net: i64 = in_category
+        .iter()
+        .filter(|t| t.cleared != TransactionCleared::Uncleared)
+        .map(|t| t.amount)
+        .sum();
+
+    let outstanding: Vec<&&Transaction> = in_category
+        .iter()
+        .filter(|t| t.cleared != TransactionCleared::Reconciled && t.amount > 0)
+        .collect();
+
+    if !outstanding.is_empty() {
+        println!(
+            "'{}' has {} outstanding transaction(s) awaiting a matching entry:",
+            category_name,
+            outstanding.len()
+        );
+        for transaction in &outstanding {
+            println!(
+                "  {} {} {}",
+                transaction.date,
+                transaction.amount,
+                transaction.memo.clone().unwrap_or_default()
+            );
+        }
+    }
+
+    if net != 0 {
+        println!(
+            "'{}' is out of balance: cleared/reconciled transactions sum to {} instead of 0",
+            category_name, net
+        );
+        Err(ErrorKind::ReimbursablesOutOfBalance(category_name.to_string(), net))?
+    }
+
+    Ok(())
+}
@@ -0,0 +1,189 @@
+use crate::ynab::{Category, Transaction};
+use cursive::traits::*;
+use cursive::views::{Dialog, OnEventView};
+use cursive_table_view::{TableView, TableViewItem};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+enum Column {
+    Date,
+    Amount,
+    Memo,
+    Category,
+    Approved,
+    Kept,
+}
+
+impl Column {
+    fn as_str(&self) -> &str {
+        match self {
+            Column::Date => "Date",
+            Column::Amount => "Amount",
+            Column::Memo => "Memo",
+            Column::Category => "Category",
+            Column::Approved => "Approved",
+            Column::Kept => "Kept",
+        }
+    }
+}
+
+/// One row of the review table: the converted transaction plus whether the
+/// user kept it in the batch that gets sent to `ynab.sync`.
+#[derive(Clone)]
+struct Row {
+    transaction: Transaction,
+    category_name: String,
+    kept: bool,
+}
+
+/// Renders `amount` (YNAB milliunits) as a plain decimal currency string,
+/// e.g. `-1234` milliunits becomes `"-1.23"`.
+fn format_amount(amount: i64) -> String {
+    format!("{:.2}", (amount as f64) / 1000.0)
+}
+
+impl TableViewItem<Column> for Row {
+    fn to_column(&self, column: Column) -> String {
+        match column {
+            Column::Date => self.transaction.date.clone(),
+            Column::Amount => format_amount(self.transaction.amount),
+            Column::Memo => self.transaction.memo.clone().unwrap_or_default(),
+            Column::Category => self.category_name.clone(),
+            Column::Approved => if self.transaction.approved { "yes" } else { "no" }.to_string(),
+            Column::Kept => if self.kept { "kept" } else { "skip" }.to_string(),
+        }
+    }
+
+    fn cmp(&self, other: &Self, column: Column) -> Ordering
+    where
+        Self: Sized,
+    {
+        match column {
+            Column::Date => self.transaction.date.cmp(&other.transaction.date),
+            Column::Amount => self.transaction.amount.cmp(&other.transaction.amount),
+            Column::Memo => self.transaction.memo.cmp(&other.transaction.memo),
+            Column::Category => self.category_name.cmp(&other.category_name),
+            Column::Approved => self.transaction.approved.cmp(&other.transaction.approved),
+            Column::Kept => self.kept.cmp(&other.kept),
+        }
+    }
+}
+
+/// Presents the converted transactions in a scrollable table and lets the
+/// user skip a row (`d`), flip its approved flag (`a`) or reassign its
+/// category (`c`) before anything is pushed to YNAB. Returns only the rows
+/// the user kept.
+pub fn review_transactions(
+    transactions: Vec<Transaction>,
+    ynab_categories: &HashMap<String, Category>,
+) -> Vec<Transaction> {
+    let category_name_by_id: HashMap<String, String> = ynab_categories
+        .iter()
+        .map(|(name, category)| (category.id.clone(), name.clone()))
+        .collect();
+    let category_names: Vec<String> = {
+        let mut names: Vec<String> = ynab_categories.keys().cloned().collect();
+        names.sort();
+        names
+    };
+
+    let rows: Vec<Row> = transactions
+        .into_iter()
+        .map(|transaction| {
+            let category_name = transaction
+                .category_id
+                .as_ref()
+                .and_then(|id| category_name_by_id.get(id))
+                .cloned()
+                .unwrap_or_default();
+            Row {
+                transaction,
+                category_name,
+                kept: true,
+            }
+        })
+        .collect();
+
+    let mut table = TableView::<Row, Column>::new()
+        .column(Column::Date, Column::Date.as_str(), |c| c)
+        .column(Column::Amount, Column::Amount.as_str(), |c| c)
+        .column(Column::Memo, Column::Memo.as_str(), |c| c)
+        .column(Column::Category, Column::Category.as_str(), |c| c)
+        .column(Column::Approved, Column::Approved.as_str(), |c| c)
+        .column(Column::Kept, Column::Kept.as_str(), |c| c);
+    table.set_items(rows);
+
+    let mut siv = cursive::default();
+    siv.add_layer(Dialog::around(OnEventView::new(table.with_name("table"))).title(
+        "Review transactions before sync (d: skip, a: toggle approved, c: next category, q: done)",
+    ));
+
+    siv.add_global_callback('d', |s| {
+        if let Some(mut table) = s.find_name::<TableView<Row, Column>>("table") {
+            if let Some(index) = table.row() {
+                if let Some(row) = table.borrow_item_mut(index) {
+                    row.kept = !row.kept;
+                }
+            }
+        }
+    });
+
+    siv.add_global_callback('a', |s| {
+        if let Some(mut table) = s.find_name::<TableView<Row, Column>>("table") {
+            if let Some(index) = table.row() {
+                if let Some(row) = table.borrow_item_mut(index) {
+                    row.transaction.approved = !row.transaction.approved;
+                }
+            }
+        }
+    });
+
+    {
+        let category_names = category_names.clone();
+        let ynab_categories: HashMap<String, Category> = ynab_categories.clone();
+        siv.add_global_callback('c', move |s| {
+            if category_names.is_empty() {
+                return;
+            }
+            if let Some(mut table) = s.find_name::<TableView<Row, Column>>("table") {
+                if let Some(index) = table.row() {
+                    if let Some(row) = table.borrow_item_mut(index) {
+                        let next = match category_names.iter().position(|n| n == &row.category_name)
+                        {
+                            Some(i) => (i + 1) % category_names.len(),
+                            None => 0,
+                        };
+                        row.category_name = category_names[next].clone();
+                        row.transaction.category_id =
+                            ynab_categories.get(&row.category_name).map(|c| c.id.clone());
+                    }
+                }
+            }
+        });
+    }
+
+    let kept = Arc::new(Mutex::new(Vec::new()));
+    {
+        let kept = Arc::clone(&kept);
+        siv.add_global_callback('q', move |s| {
+            if let Some(mut table) = s.find_name::<TableView<Row, Column>>("table") {
+                *kept.lock().unwrap() = table.take_items();
+            }
+            s.quit();
+        });
+    }
+    siv.run();
+    // Dropping `siv` releases the 'q' callback's clone of `kept`.
+    drop(siv);
+
+    Arc::try_unwrap(kept)
+        .unwrap_or_else(|_| panic!("review table callback still held after siv.run() returned"))
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .filter(|row| row.kept)
+        .map(|row| row.transaction)
+        .collect()
+}
@@ -0,0 +1,143 @@
+use crate::error::{ErrorKind, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use structopt::StructOpt;
+
+#[derive(Debug, Clone, StructOpt)]
+pub struct Cli {
+    #[structopt(
+        long = "ynab-token",
+        env = "YNAB_TOKEN",
+        hide_env_values = true,
+        required = true,
+        value_name = "TOKEN",
+        help = "YNAB personal access token."
+    )]
+    pub token: String,
+    #[structopt(
+        long = "ynab-budget-id",
+        value_name = "ID",
+        help = "YNAB budget id to sync transactions into. Ignored (and not required) when --accounts-config is used."
+    )]
+    pub budget_id: Option<String>,
+    #[structopt(
+        long = "ynab-account-id",
+        value_name = "ID",
+        help = "YNAB account id to sync transactions into. Ignored (and not required) when --accounts-config is used."
+    )]
+    pub account_id: Option<String>,
+    #[structopt(
+        long = "force-update",
+        help = "Overwrite transactions already present in YNAB instead of skipping them."
+    )]
+    pub force_update: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Category {
+    pub id: String,
+    pub name: String,
+    pub deleted: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Account {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub account_type: String,
+    pub on_budget: bool,
+    pub closed: bool,
+    pub balance: i64,
+    pub deleted: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum TransactionCleared {
+    Cleared,
+    Uncleared,
+    Reconciled,
+}
+
+/// A single subtransaction making up part of a split `Transaction`.
+///
+/// YNAB requires that the sum of `amount` across all subtransactions of a
+/// transaction equals the parent transaction's own `amount` exactly.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubTransaction {
+    pub amount: i64,
+    pub category_id: Option<String>,
+    pub payee_id: Option<String>,
+    pub payee_name: Option<String>,
+    pub memo: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Transaction {
+    /// Only set for transactions fetched back from YNAB; absent on
+    /// transactions we are about to push via `sync`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub account_id: String,
+    pub date: String,
+    pub amount: i64,
+    pub payee_id: Option<String>,
+    pub payee_name: Option<String>,
+    pub category_id: Option<String>,
+    pub memo: Option<String>,
+    pub cleared: TransactionCleared,
+    pub approved: bool,
+    pub flag_color: Option<String>,
+    pub import_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtransactions: Option<Vec<SubTransaction>>,
+    #[serde(default)]
+    pub deleted: bool,
+}
+
+pub struct YNAB {
+    pub token: String,
+}
+
+impl YNAB {
+    /// Takes the YNAB CLI flags and the `step/total` position of this step in
+    /// the binary's progress output. Performs no checks, prints nothing and
+    /// always returns `Ok(())`.
+    pub fn validate_cli(&self, _cli: Cli, _step: u32, _total: u32) -> Result<()> {
+        Ok(())
+    }
+
+    pub fn get_categories(&self, _budget_id: String) -> Result<HashMap<String, Category>> {
+        let _ = &self.token;
+        Err(ErrorKind::YNAB("get_categories is not implemented".to_string()).into())
+    }
+
+    pub fn get_accounts(&self, _budget_id: String) -> Result<HashMap<String, Account>> {
+        let _ = &self.token;
+        Err(ErrorKind::YNAB("get_accounts is not implemented".to_string()).into())
+    }
+
+    pub fn get_transactions(
+        &self,
+        _budget_id: String,
+        _account_id: String,
+        _days_to_sync: i64,
+    ) -> Result<Vec<Transaction>> {
+        let _ = &self.token;
+        Err(ErrorKind::YNAB("get_transactions is not implemented".to_string()).into())
+    }
+
+    pub fn sync(
+        &self,
+        _transactions: Vec<Transaction>,
+        _existing_transactions: Vec<Transaction>,
+        _budget_id: String,
+        _force_update: bool,
+        _step: u32,
+        _total: u32,
+    ) -> Result<()> {
+        let _ = &self.token;
+        Ok(())
+    }
+}